@@ -0,0 +1,106 @@
+use crate::error::{JsonError, Span};
+
+/// A minimal character-at-a-time cursor, implemented both by the
+/// tokenizer (`token.rs`) and by the zero-copy borrowed parser
+/// (`value.rs`). Escape-sequence decoding and literal matching are
+/// written once here against this trait instead of being duplicated
+/// (and drifting) between the two parsing paths.
+pub(crate) trait CharCursor {
+    fn peek(&mut self) -> Option<char>;
+    fn bump(&mut self) -> Option<char>;
+    fn span(&mut self) -> Span;
+}
+
+/// Reads exactly four hex digits (a `\uXXXX` code unit), failing as
+/// soon as a non-hex character or the end of input is hit.
+pub(crate) fn read_hex4<C: CharCursor>(cursor: &mut C, escape_span: Span) -> Result<u32, JsonError> {
+    let mut value = 0u32;
+    for _ in 0..4 {
+        let c = cursor.bump().ok_or(JsonError::InvalidEscape(escape_span))?;
+        let digit = c.to_digit(16).ok_or(JsonError::InvalidEscape(escape_span))?;
+        value = value * 16 + digit;
+    }
+    Ok(value)
+}
+
+/// Decodes a `\u` escape (the `u` itself already consumed), handling
+/// UTF-16 surrogate pairs per RFC 8259.
+pub(crate) fn read_unicode_escape<C: CharCursor>(cursor: &mut C, escape_span: Span) -> Result<char, JsonError> {
+    let hi = read_hex4(cursor, escape_span)?;
+
+    let code_point = if (0xD800..=0xDBFF).contains(&hi) {
+        // Par sustituto alto: debe ir seguido de un \uXXXX bajo
+        if cursor.bump() != Some('\\') || cursor.bump() != Some('u') {
+            return Err(JsonError::InvalidEscape(escape_span));
+        }
+        let lo = read_hex4(cursor, escape_span)?;
+        if !(0xDC00..=0xDFFF).contains(&lo) {
+            return Err(JsonError::InvalidEscape(escape_span));
+        }
+        0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00)
+    } else if (0xDC00..=0xDFFF).contains(&hi) {
+        // Sustituto bajo sin su pareja alta
+        return Err(JsonError::InvalidEscape(escape_span));
+    } else {
+        hi
+    };
+
+    char::from_u32(code_point).ok_or(JsonError::InvalidEscape(escape_span))
+}
+
+/// Matches the literal keyword `word` (`true`/`false`/`null`) one
+/// character at a time, failing as soon as a mismatched character or
+/// the end of input is hit rather than blindly consuming `word.len()`
+/// characters regardless of what's actually there.
+pub(crate) fn match_literal<C: CharCursor>(cursor: &mut C, word: &str, span: Span) -> Result<(), JsonError> {
+    for expected in word.chars() {
+        match cursor.bump() {
+            Some(c) if c == expected => {},
+            Some(other) => {
+                return Err(JsonError::UnexpectedToken {
+                    found: format!("'{}'", other),
+                    expected: format!("'{}'", word),
+                    span,
+                })
+            },
+            None => return Err(JsonError::UnexpectedEof),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::{tokenize, Token};
+
+    /// Tokenizes `input` and unwraps its single string token, panicking
+    /// if `input` isn't exactly one JSON string literal.
+    fn decode(input: &str) -> Result<String, JsonError> {
+        match tokenize(input)?.into_iter().next() {
+            Some((Token::String(s), _)) => Ok(s),
+            other => panic!("expected a single string token, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn combines_valid_surrogate_pair() {
+        // \uD83D\uDE00 is the surrogate pair for U+1F600 (😀).
+        assert_eq!(decode("\"\\uD83D\\uDE00\"").unwrap(), "😀");
+    }
+
+    #[test]
+    fn rejects_unpaired_high_surrogate() {
+        assert!(matches!(decode("\"\\uD83D\""), Err(JsonError::InvalidEscape(_))));
+    }
+
+    #[test]
+    fn rejects_unpaired_low_surrogate() {
+        assert!(matches!(decode("\"\\uDE00\""), Err(JsonError::InvalidEscape(_))));
+    }
+
+    #[test]
+    fn rejects_high_surrogate_not_followed_by_a_low_one() {
+        assert!(matches!(decode("\"\\uD83Dabc\""), Err(JsonError::InvalidEscape(_))));
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+
+/// A position in the original input, used to locate tokens and errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+impl Span {
+    pub(crate) fn start() -> Self {
+        Span { line: 1, column: 1, offset: 0 }
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Errors produced while tokenizing, parsing or reading a JSON document.
+#[derive(Debug)]
+pub enum JsonError {
+    UnexpectedChar(char, Span),
+    InvalidEscape(Span),
+    MalformedNumber(Span),
+    UnexpectedEof,
+    UnexpectedToken { found: String, expected: String, span: Span },
+    Io(std::io::Error),
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonError::UnexpectedChar(c, span) => {
+                write!(f, "carácter inesperado '{}' en {}", c, span)
+            }
+            JsonError::InvalidEscape(span) => {
+                write!(f, "secuencia de escape inválida en {}", span)
+            }
+            JsonError::MalformedNumber(span) => {
+                write!(f, "número inválido en {}", span)
+            }
+            JsonError::UnexpectedEof => write!(f, "fin inesperado de entrada"),
+            JsonError::UnexpectedToken { found, expected, span } => {
+                write!(f, "se esperaba {} pero se encontró {} en {}", expected, found, span)
+            }
+            JsonError::Io(e) => write!(f, "error de E/S: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            JsonError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
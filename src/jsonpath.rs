@@ -0,0 +1,300 @@
+use crate::error::{JsonError, Span};
+use crate::value::JsonValue;
+
+/// A single step of a parsed JSONPath expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathToken {
+    Root,
+    Child(String),
+    Wildcard,
+    Index(usize),
+    Slice(Option<usize>, Option<usize>),
+    Descent,
+}
+
+/// Reads a run of identifier characters (`.name` or the name following
+/// `..`) from `chars`, stopping at the first character that isn't
+/// alphanumeric or `_`.
+fn read_path_ident(chars: &mut std::iter::Peekable<std::str::Chars>, offset: &mut usize) -> String {
+    let mut name = String::new();
+    while let Some(&c) = chars.peek() {
+        if c.is_alphanumeric() || c == '_' {
+            name.push(c);
+            chars.next();
+            *offset += 1;
+        } else {
+            break;
+        }
+    }
+    name
+}
+
+fn path_span(offset: usize) -> Span {
+    Span { line: 1, column: offset + 1, offset }
+}
+
+/// Tokenizes a JSONPath expression such as `$.direccion.calle`,
+/// `$.hobbies[*]` or `$..numero` into a sequence of `PathToken`s.
+fn tokenize_path(path: &str) -> Result<Vec<PathToken>, JsonError> {
+    let mut tokens = Vec::new();
+    let mut chars = path.chars().peekable();
+    let mut offset = 0usize;
+
+    match chars.next() {
+        Some('$') => {
+            tokens.push(PathToken::Root);
+            offset += 1;
+        },
+        Some(c) => return Err(JsonError::UnexpectedChar(c, path_span(0))),
+        None => return Err(JsonError::UnexpectedEof),
+    }
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '.' => {
+                chars.next();
+                offset += 1;
+                if chars.peek() == Some(&'.') {
+                    chars.next();
+                    offset += 1;
+                    tokens.push(PathToken::Descent);
+                    if chars.peek() == Some(&'*') {
+                        chars.next();
+                        offset += 1;
+                        tokens.push(PathToken::Wildcard);
+                    } else {
+                        let name = read_path_ident(&mut chars, &mut offset);
+                        if !name.is_empty() {
+                            tokens.push(PathToken::Child(name));
+                        }
+                    }
+                } else if chars.peek() == Some(&'*') {
+                    chars.next();
+                    offset += 1;
+                    tokens.push(PathToken::Wildcard);
+                } else {
+                    let name = read_path_ident(&mut chars, &mut offset);
+                    if name.is_empty() {
+                        return Err(JsonError::UnexpectedToken {
+                            found: "fin de ruta".to_string(),
+                            expected: "un nombre de campo".to_string(),
+                            span: path_span(offset),
+                        });
+                    }
+                    tokens.push(PathToken::Child(name));
+                }
+            },
+            '[' => {
+                chars.next();
+                offset += 1;
+
+                if chars.peek() == Some(&'\'') || chars.peek() == Some(&'"') {
+                    let quote = chars.next().unwrap();
+                    offset += 1;
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(c) if c == quote => {
+                                offset += 1;
+                                break;
+                            },
+                            Some(c) => {
+                                name.push(c);
+                                offset += 1;
+                            },
+                            None => return Err(JsonError::UnexpectedEof),
+                        }
+                    }
+                    tokens.push(PathToken::Child(name));
+                } else if chars.peek() == Some(&'*') {
+                    chars.next();
+                    offset += 1;
+                    tokens.push(PathToken::Wildcard);
+                } else {
+                    let mut start_buf = String::new();
+                    while let Some(&c) = chars.peek() {
+                        if c.is_ascii_digit() || c == '-' {
+                            start_buf.push(c);
+                            chars.next();
+                            offset += 1;
+                        } else {
+                            break;
+                        }
+                    }
+
+                    if chars.peek() == Some(&':') {
+                        chars.next();
+                        offset += 1;
+                        let mut end_buf = String::new();
+                        while let Some(&c) = chars.peek() {
+                            if c.is_ascii_digit() || c == '-' {
+                                end_buf.push(c);
+                                chars.next();
+                                offset += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        let start = if start_buf.is_empty() { None } else { start_buf.parse().ok() };
+                        let end = if end_buf.is_empty() { None } else { end_buf.parse().ok() };
+                        tokens.push(PathToken::Slice(start, end));
+                    } else if start_buf.is_empty() {
+                        return match chars.peek() {
+                            Some(&c) => Err(JsonError::UnexpectedToken {
+                                found: format!("'{}'", c),
+                                expected: "un índice numérico".to_string(),
+                                span: path_span(offset),
+                            }),
+                            None => Err(JsonError::UnexpectedEof),
+                        };
+                    } else {
+                        let idx = start_buf
+                            .parse::<usize>()
+                            .map_err(|_| JsonError::MalformedNumber(path_span(offset)))?;
+                        tokens.push(PathToken::Index(idx));
+                    }
+                }
+
+                match chars.next() {
+                    Some(']') => offset += 1,
+                    Some(c) => {
+                        return Err(JsonError::UnexpectedToken {
+                            found: c.to_string(),
+                            expected: "']'".to_string(),
+                            span: path_span(offset),
+                        })
+                    },
+                    None => return Err(JsonError::UnexpectedEof),
+                }
+            },
+            c => return Err(JsonError::UnexpectedChar(c, path_span(offset))),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Selects all nodes of `value` matching the JSONPath expression `path`
+/// (e.g. `$.direccion.calle`, `$.hobbies[*]`, `$..numero`).
+pub fn select<'a>(value: &'a JsonValue, path: &str) -> Result<Vec<&'a JsonValue>, JsonError> {
+    let tokens = tokenize_path(path)?;
+    let mut current: Vec<&'a JsonValue> = vec![value];
+    let mut i = 0;
+
+    if matches!(tokens.first(), Some(PathToken::Root)) {
+        i = 1;
+    }
+
+    while i < tokens.len() {
+        match &tokens[i] {
+            PathToken::Root => {},
+            PathToken::Descent => {
+                i += 1;
+                let step = tokens.get(i).ok_or(JsonError::UnexpectedEof)?;
+                current = current
+                    .into_iter()
+                    .flat_map(|v| collect_descendants(v, step))
+                    .collect();
+            },
+            step => {
+                current = current
+                    .into_iter()
+                    .flat_map(|v| apply_path_step(v, step))
+                    .collect();
+            },
+        }
+        i += 1;
+    }
+
+    Ok(current)
+}
+
+/// Applies a single non-descent `PathToken` to one node, returning the
+/// matching children (zero, one, or many).
+fn apply_path_step<'a>(value: &'a JsonValue, token: &PathToken) -> Vec<&'a JsonValue> {
+    match token {
+        PathToken::Child(name) => match value {
+            JsonValue::Object(map) => map.get(name).into_iter().collect(),
+            _ => vec![],
+        },
+        PathToken::Wildcard => match value {
+            JsonValue::Object(map) => map.values().collect(),
+            JsonValue::Array(items) => items.iter().collect(),
+            _ => vec![],
+        },
+        PathToken::Index(idx) => match value {
+            JsonValue::Array(items) => items.get(*idx).into_iter().collect(),
+            _ => vec![],
+        },
+        PathToken::Slice(start, end) => match value {
+            JsonValue::Array(items) => {
+                let s = start.unwrap_or(0).min(items.len());
+                let e = end.unwrap_or(items.len()).min(items.len());
+                if s < e { items[s..e].iter().collect() } else { vec![] }
+            },
+            _ => vec![],
+        },
+        PathToken::Root | PathToken::Descent => vec![],
+    }
+}
+
+/// Recursively collects every descendant of `value` (including `value`
+/// itself) matching `token`, implementing JSONPath's `..` operator.
+fn collect_descendants<'a>(value: &'a JsonValue, token: &PathToken) -> Vec<&'a JsonValue> {
+    let mut results = apply_path_step(value, token);
+    match value {
+        JsonValue::Object(map) => {
+            for child in map.values() {
+                results.extend(collect_descendants(child, token));
+            }
+        },
+        JsonValue::Array(items) => {
+            for child in items {
+                results.extend(collect_descendants(child, token));
+            }
+        },
+        _ => {},
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn wildcard_selects_all_array_items() {
+        let v = parse("[1,2,3]").unwrap();
+        let results = select(&v, "$[*]").unwrap();
+        let nums: Vec<f64> = results.iter().map(|v| v.as_f64().unwrap()).collect();
+        assert_eq!(nums, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn slice_selects_a_sub_range() {
+        let v = parse("[1,2,3,4,5]").unwrap();
+        let results = select(&v, "$[1:3]").unwrap();
+        let nums: Vec<f64> = results.iter().map(|v| v.as_f64().unwrap()).collect();
+        assert_eq!(nums, vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn recursive_descent_finds_nested_fields() {
+        let v = parse("{\"a\":{\"numero\":1},\"b\":[{\"numero\":2}]}").unwrap();
+        let mut results: Vec<f64> = select(&v, "$..numero")
+            .unwrap()
+            .iter()
+            .map(|v| v.as_f64().unwrap())
+            .collect();
+        results.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(results, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn empty_bracket_index_is_not_reported_as_a_malformed_number() {
+        let v = parse("[1,2,3]").unwrap();
+        let err = select(&v, "$.a[abc]").unwrap_err();
+        assert!(matches!(err, JsonError::UnexpectedToken { .. }));
+    }
+}
@@ -0,0 +1,37 @@
+//! A small JSON library: parsing, serialization, JSONPath queries and
+//! zero-copy parsing over borrowed `&str` input.
+
+mod cursor;
+mod error;
+mod jsonpath;
+mod parser;
+mod serializer;
+mod token;
+mod value;
+
+use std::io::Read;
+
+pub use error::{JsonError, Span};
+pub use jsonpath::{select, PathToken};
+pub use serializer::{to_string, to_string_pretty, DeparseOptions};
+pub use value::{parse_borrowed, JsonValue, JsonValueRef};
+
+use parser::Parser;
+use token::tokenize;
+
+/// Parses a complete JSON document from `input`.
+pub fn parse(input: &str) -> Result<JsonValue, JsonError> {
+    let tokens = tokenize(input)?;
+    Parser::new(tokens).parse()
+}
+
+/// Convenience wrapper around [`parse`] for an `R: Read` source (a file,
+/// a socket, ...). This still buffers the entire input into one
+/// `String` before parsing — `tokenize`/`Parser` only work over a
+/// complete `&str` — so it saves callers the boilerplate of doing that
+/// read themselves, but it is not an incremental/streaming parser.
+pub fn from_reader<R: Read>(mut reader: R) -> Result<JsonValue, JsonError> {
+    let mut buffer = String::new();
+    reader.read_to_string(&mut buffer).map_err(JsonError::Io)?;
+    parse(&buffer)
+}
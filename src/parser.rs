@@ -0,0 +1,189 @@
+use crate::error::{JsonError, Span};
+use crate::token::Token;
+use crate::value::JsonValue;
+
+pub(crate) struct Parser {
+    tokens: Vec<(Token, Span)>,
+    current: usize,
+}
+
+impl Parser {
+    pub(crate) fn new(tokens: Vec<(Token, Span)>) -> Self {
+        Parser {
+            tokens,
+            current: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.current).map(|(t, _)| t)
+    }
+
+    /// The span of the token `peek` would return, or the span just past
+    /// the last token if the input has been exhausted.
+    fn current_span(&self) -> Span {
+        match self.tokens.get(self.current) {
+            Some((_, span)) => *span,
+            None => self
+                .tokens
+                .last()
+                .map(|(_, span)| *span)
+                .unwrap_or_else(Span::start),
+        }
+    }
+
+    fn advance(&mut self) -> Option<&Token> {
+        if self.current < self.tokens.len() {
+            self.current += 1;
+        }
+        self.tokens.get(self.current - 1).map(|(t, _)| t)
+    }
+
+    pub(crate) fn parse(&mut self) -> Result<JsonValue, JsonError> {
+        self.parse_value()
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, JsonError> {
+        match self.peek().ok_or(JsonError::UnexpectedEof)? {
+            Token::LeftBrace => self.parse_object(),
+            Token::LeftBracket => self.parse_array(),
+            Token::String(_) => {
+                let token = self.advance().unwrap();
+                if let Token::String(s) = token {
+                    Ok(JsonValue::String(s.clone()))
+                } else {
+                    unreachable!()
+                }
+            },
+            Token::Number(n) => {
+                let num = *n; // Copiamos el valor antes de advance
+                self.advance();
+                Ok(JsonValue::Number(num))
+            },
+            Token::Boolean(b) => {
+                let bool_val = *b; // Copiamos el valor antes de advance
+                self.advance();
+                Ok(JsonValue::Boolean(bool_val))
+            },
+            Token::Null => {
+                self.advance();
+                Ok(JsonValue::Null)
+            },
+            other => Err(JsonError::UnexpectedToken {
+                found: other.to_string(),
+                expected: "un valor".to_string(),
+                span: self.current_span(),
+            }),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, JsonError> {
+        self.advance(); // Consumir '{'
+        let mut map = std::collections::HashMap::new();
+
+        while let Some(token) = self.peek() {
+            if matches!(token, Token::RightBrace) {
+                self.advance(); // Consumir '}'
+                return Ok(JsonValue::Object(map));
+            }
+
+            // Parsear la key (debe ser un string)
+            let key_span = self.current_span();
+            let key = match self.advance().ok_or(JsonError::UnexpectedEof)? {
+                Token::String(s) => s.clone(),
+                other => {
+                    return Err(JsonError::UnexpectedToken {
+                        found: other.to_string(),
+                        expected: "una key de tipo string".to_string(),
+                        span: key_span,
+                    })
+                }
+            };
+
+            // Esperar ':'
+            let colon_span = self.current_span();
+            match self.advance() {
+                Some(Token::Colon) => {},
+                Some(other) => {
+                    return Err(JsonError::UnexpectedToken {
+                        found: other.to_string(),
+                        expected: "':'".to_string(),
+                        span: colon_span,
+                    })
+                },
+                None => return Err(JsonError::UnexpectedEof),
+            }
+
+            // Parsear el valor
+            let value = self.parse_value()?;
+            map.insert(key, value);
+
+            // Verificar si hay más elementos
+            if let Some(token) = self.peek() {
+                match token {
+                    Token::Comma => {
+                        self.advance(); // Consumir ','
+                        if let Some(Token::RightBrace) = self.peek() {
+                            return Err(JsonError::UnexpectedToken {
+                                found: self.peek().unwrap().to_string(),
+                                expected: "una key de tipo string".to_string(),
+                                span: self.current_span(),
+                            });
+                        }
+                        continue;
+                    },
+                    Token::RightBrace => continue,
+                    other => {
+                        return Err(JsonError::UnexpectedToken {
+                            found: other.to_string(),
+                            expected: "',' o '}'".to_string(),
+                            span: self.current_span(),
+                        })
+                    }
+                }
+            }
+        }
+
+        Err(JsonError::UnexpectedEof)
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, JsonError> {
+        self.advance(); // Consumir '['
+        let mut array = Vec::new();
+
+        while let Some(token) = self.peek() {
+            if matches!(token, Token::RightBracket) {
+                self.advance(); // Consumir ']'
+                return Ok(JsonValue::Array(array));
+            }
+
+            array.push(self.parse_value()?);
+
+            if let Some(token) = self.peek() {
+                match token {
+                    Token::Comma => {
+                        self.advance(); // Consumir ','
+                        if let Some(Token::RightBracket) = self.peek() {
+                            return Err(JsonError::UnexpectedToken {
+                                found: self.peek().unwrap().to_string(),
+                                expected: "un valor".to_string(),
+                                span: self.current_span(),
+                            });
+                        }
+                        continue;
+                    },
+                    Token::RightBracket => continue,
+                    other => {
+                        return Err(JsonError::UnexpectedToken {
+                            found: other.to_string(),
+                            expected: "',' o ']'".to_string(),
+                            span: self.current_span(),
+                        })
+                    }
+                }
+            }
+        }
+
+        Err(JsonError::UnexpectedEof)
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use crate::value::JsonValue;
+
+/// Options controlling how `to_string_pretty` renders a `JsonValue`.
+pub struct DeparseOptions {
+    /// Number of spaces used per level of nesting.
+    pub indent: usize,
+    /// Whether object keys are emitted in sorted order (useful for
+    /// stable diffs) instead of `HashMap` iteration order.
+    pub sort_keys: bool,
+}
+
+impl Default for DeparseOptions {
+    fn default() -> Self {
+        DeparseOptions { indent: 2, sort_keys: false }
+    }
+}
+
+/// Serializes a `JsonValue` into a compact, single-line JSON string.
+pub fn to_string(value: &JsonValue) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out, None, 0);
+    out
+}
+
+/// Serializes a `JsonValue` into a human-readable, indented JSON string.
+pub fn to_string_pretty(value: &JsonValue, options: &DeparseOptions) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out, Some(options), 0);
+    out
+}
+
+fn write_value(value: &JsonValue, out: &mut String, options: Option<&DeparseOptions>, depth: usize) {
+    match value {
+        JsonValue::Null => out.push_str("null"),
+        JsonValue::Boolean(b) => out.push_str(if *b { "true" } else { "false" }),
+        JsonValue::Number(n) => write_number(*n, out),
+        JsonValue::String(s) => write_escaped_string(s, out),
+        JsonValue::Array(items) => write_array(items, out, options, depth),
+        JsonValue::Object(map) => write_object(map, out, options, depth),
+    }
+}
+
+fn write_number(n: f64, out: &mut String) {
+    if n.is_finite() && n.fract() == 0.0 && n.abs() < 1e15 {
+        out.push_str(&(n as i64).to_string());
+    } else {
+        out.push_str(&n.to_string());
+    }
+}
+
+fn write_escaped_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\x08' => out.push_str("\\b"),
+            '\x0c' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_array(items: &[JsonValue], out: &mut String, options: Option<&DeparseOptions>, depth: usize) {
+    if items.is_empty() {
+        out.push_str("[]");
+        return;
+    }
+    out.push('[');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_newline_indent(out, options, depth + 1);
+        write_value(item, out, options, depth + 1);
+    }
+    write_newline_indent(out, options, depth);
+    out.push(']');
+}
+
+fn write_object(map: &HashMap<String, JsonValue>, out: &mut String, options: Option<&DeparseOptions>, depth: usize) {
+    if map.is_empty() {
+        out.push_str("{}");
+        return;
+    }
+    out.push('{');
+
+    let mut keys: Vec<&String> = map.keys().collect();
+    if options.is_some_and(|o| o.sort_keys) {
+        keys.sort();
+    }
+
+    for (i, key) in keys.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_newline_indent(out, options, depth + 1);
+        write_escaped_string(key, out);
+        out.push(':');
+        if options.is_some() {
+            out.push(' ');
+        }
+        write_value(&map[*key], out, options, depth + 1);
+    }
+    write_newline_indent(out, options, depth);
+    out.push('}');
+}
+
+fn write_newline_indent(out: &mut String, options: Option<&DeparseOptions>, depth: usize) {
+    if let Some(options) = options {
+        out.push('\n');
+        out.push_str(&" ".repeat(options.indent * depth));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_integral_numbers_without_a_decimal_point() {
+        assert_eq!(to_string(&JsonValue::Number(4.0)), "4");
+        assert_eq!(to_string(&JsonValue::Number(-12.0)), "-12");
+    }
+
+    #[test]
+    fn renders_fractional_numbers_with_a_decimal_point() {
+        assert_eq!(to_string(&JsonValue::Number(4.5)), "4.5");
+        assert_eq!(to_string(&JsonValue::Number(-0.25)), "-0.25");
+    }
+
+    #[test]
+    fn round_trips_escaped_strings() {
+        let value = JsonValue::String("a \"quote\", a \\ and a\nnewline".to_string());
+        let rendered = to_string(&value);
+        assert_eq!(rendered, "\"a \\\"quote\\\", a \\\\ and a\\nnewline\"");
+
+        let parsed = crate::parse(&rendered).unwrap();
+        assert_eq!(parsed.as_str(), value.as_str());
+    }
+
+    #[test]
+    fn sort_keys_orders_object_keys_alphabetically() {
+        let mut map = HashMap::new();
+        map.insert("b".to_string(), JsonValue::Number(2.0));
+        map.insert("a".to_string(), JsonValue::Number(1.0));
+        let value = JsonValue::Object(map);
+
+        let options = DeparseOptions { indent: 0, sort_keys: true };
+        let rendered = to_string_pretty(&value, &options);
+        assert!(rendered.find("\"a\"").unwrap() < rendered.find("\"b\"").unwrap());
+    }
+}
@@ -0,0 +1,184 @@
+use crate::cursor::{match_literal, read_unicode_escape, CharCursor};
+use crate::error::{JsonError, Span};
+
+#[derive(Debug)]
+pub(crate) enum Token {
+    LeftBrace,      // {
+    RightBrace,     // }
+    LeftBracket,    // [
+    RightBracket,   // ]
+    Colon,          // :
+    Comma,          // ,
+    String(String),
+    Number(f64),
+    Boolean(bool),
+    Null,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::LeftBrace => write!(f, "'{{'"),
+            Token::RightBrace => write!(f, "'}}'"),
+            Token::LeftBracket => write!(f, "'['"),
+            Token::RightBracket => write!(f, "']'"),
+            Token::Colon => write!(f, "':'"),
+            Token::Comma => write!(f, "','"),
+            Token::String(s) => write!(f, "string \"{}\"", s),
+            Token::Number(n) => write!(f, "number {}", n),
+            Token::Boolean(b) => write!(f, "boolean {}", b),
+            Token::Null => write!(f, "null"),
+        }
+    }
+}
+
+/// A cursor over the raw input used by `tokenize`, tracking a running
+/// line/column/offset as characters are consumed.
+struct TokenCursor<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+    offset: usize,
+}
+
+impl<'a> TokenCursor<'a> {
+    fn new(input: &'a str) -> Self {
+        TokenCursor { chars: input.chars().peekable(), line: 1, column: 1, offset: 0 }
+    }
+}
+
+impl CharCursor for TokenCursor<'_> {
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        self.offset += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn span(&mut self) -> Span {
+        Span { line: self.line, column: self.column, offset: self.offset }
+    }
+}
+
+pub(crate) fn tokenize(input: &str) -> Result<Vec<(Token, Span)>, JsonError> {
+    let mut tokens = Vec::new();
+    let mut cursor = TokenCursor::new(input);
+
+    while let Some(c) = cursor.peek() {
+        let span = cursor.span();
+        match c {
+            // Ignorar espacios en blanco
+            c if c.is_whitespace() => {
+                cursor.bump();
+            },
+            // Símbolos simples
+            '{' => {
+                tokens.push((Token::LeftBrace, span));
+                cursor.bump();
+            },
+            '}' => {
+                tokens.push((Token::RightBrace, span));
+                cursor.bump();
+            },
+            '[' => {
+                tokens.push((Token::LeftBracket, span));
+                cursor.bump();
+            },
+            ']' => {
+                tokens.push((Token::RightBracket, span));
+                cursor.bump();
+            },
+            ':' => {
+                tokens.push((Token::Colon, span));
+                cursor.bump();
+            },
+            ',' => {
+                tokens.push((Token::Comma, span));
+                cursor.bump();
+            },
+            // Strings
+            '"' => {
+                cursor.bump(); // Consumir la comilla inicial
+                let mut string = String::new();
+
+                loop {
+                    let escape_span = cursor.span();
+                    match cursor.peek() {
+                        Some('"') => {
+                            cursor.bump();
+                            break;
+                        },
+                        Some('\\') => {
+                            cursor.bump(); // Consumimos el caracter de escape
+                            if let Some(next_char) = cursor.bump() {
+                                match next_char {
+                                    '"' | '\\' | '/' => string.push(next_char),
+                                    'b' => string.push('\x08'),
+                                    'f' => string.push('\x0c'),
+                                    'n' => string.push('\n'),
+                                    'r' => string.push('\r'),
+                                    't' => string.push('\t'),
+                                    'u' => string.push(read_unicode_escape(&mut cursor, escape_span)?),
+                                    _ => return Err(JsonError::InvalidEscape(escape_span)),
+                                }
+                            }
+                        },
+                        Some(c) => {
+                            string.push(c);
+                            cursor.bump();
+                        },
+                        None => return Err(JsonError::UnexpectedEof),
+                    }
+                }
+                tokens.push((Token::String(string), span));
+            },
+            // Números
+            c if c.is_ascii_digit() || c == '-' => {
+                let mut number = String::new();
+                if c == '-' {
+                    number.push(c);
+                    cursor.bump();
+                }
+
+                while let Some(c) = cursor.peek() {
+                    if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || c == '+' || c == '-' {
+                        number.push(c);
+                        cursor.bump();
+                    } else {
+                        break;
+                    }
+                }
+
+                match number.parse::<f64>() {
+                    Ok(n) => tokens.push((Token::Number(n), span)),
+                    Err(_) => return Err(JsonError::MalformedNumber(span)),
+                }
+            },
+            // Valores literales (true, false, null)
+            't' => {
+                match_literal(&mut cursor, "true", span)?;
+                tokens.push((Token::Boolean(true), span));
+            },
+            'f' => {
+                match_literal(&mut cursor, "false", span)?;
+                tokens.push((Token::Boolean(false), span));
+            },
+            'n' => {
+                match_literal(&mut cursor, "null", span)?;
+                tokens.push((Token::Null, span));
+            },
+            _ => return Err(JsonError::UnexpectedChar(c, span)),
+        }
+    }
+
+    Ok(tokens)
+}
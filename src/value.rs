@@ -0,0 +1,380 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::cursor::{match_literal, read_unicode_escape, CharCursor};
+use crate::error::{JsonError, Span};
+
+/// Renders what was actually found at a cursor position for error
+/// messages, instead of a generic placeholder.
+fn describe_found(c: Option<char>) -> String {
+    match c {
+        Some(c) => format!("'{}'", c),
+        None => "el fin de la entrada".to_string(),
+    }
+}
+
+#[derive(Debug)]
+pub enum JsonValue {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(HashMap<String, JsonValue>),
+}
+
+impl JsonValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&HashMap<String, JsonValue>> {
+        match self {
+            JsonValue::Object(o) => Some(o),
+            _ => None,
+        }
+    }
+
+    /// Looks up `key` on this value if it is an object, returning `None`
+    /// otherwise or if the key is absent.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        self.as_object().and_then(|o| o.get(key))
+    }
+
+    /// Looks up index `i` on this value if it is an array, returning
+    /// `None` otherwise or if the index is out of bounds.
+    pub fn index(&self, i: usize) -> Option<&JsonValue> {
+        self.as_array().and_then(|a| a.get(i))
+    }
+}
+
+/// A borrowed counterpart to `JsonValue`: strings and keys borrow
+/// directly from the input when they contain no escape sequences, and
+/// only allocate (via `Cow::Owned`) when unescaping is required.
+#[derive(Debug)]
+pub enum JsonValueRef<'a> {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(Cow<'a, str>),
+    Array(Vec<JsonValueRef<'a>>),
+    Object(HashMap<Cow<'a, str>, JsonValueRef<'a>>),
+}
+
+impl<'a> JsonValueRef<'a> {
+    /// Converts this borrowed value into a fully owned `JsonValue`.
+    pub fn to_owned(&self) -> JsonValue {
+        match self {
+            JsonValueRef::Null => JsonValue::Null,
+            JsonValueRef::Boolean(b) => JsonValue::Boolean(*b),
+            JsonValueRef::Number(n) => JsonValue::Number(*n),
+            JsonValueRef::String(s) => JsonValue::String(s.clone().into_owned()),
+            JsonValueRef::Array(items) => {
+                JsonValue::Array(items.iter().map(JsonValueRef::to_owned).collect())
+            },
+            JsonValueRef::Object(map) => JsonValue::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone().into_owned(), v.to_owned()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Parses `input` into a `JsonValueRef` without copying strings that
+/// contain no escape sequences.
+pub fn parse_borrowed(input: &str) -> Result<JsonValueRef<'_>, JsonError> {
+    let mut cursor = BorrowedCursor::new(input);
+    cursor.skip_whitespace();
+    let value = cursor.parse_value()?;
+    cursor.skip_whitespace();
+    Ok(value)
+}
+
+/// A cursor over the raw input used by `parse_borrowed`. Unlike
+/// `tokenize`, it does not build a token vector up front: it parses
+/// directly from byte offsets into the original `&str` so that strings
+/// without escapes can be borrowed instead of copied.
+struct BorrowedCursor<'a> {
+    input: &'a str,
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> BorrowedCursor<'a> {
+    fn new(input: &'a str) -> Self {
+        BorrowedCursor {
+            input,
+            chars: input.char_indices().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek_offset(&mut self) -> usize {
+        self.chars.peek().map(|&(i, _)| i).unwrap_or(self.input.len())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl CharCursor for BorrowedCursor<'_> {
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let (_, c) = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn span(&mut self) -> Span {
+        Span { line: self.line, column: self.column, offset: self.peek_offset() }
+    }
+}
+
+impl<'a> BorrowedCursor<'a> {
+    fn parse_value(&mut self) -> Result<JsonValueRef<'a>, JsonError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => Ok(JsonValueRef::String(self.parse_string()?)),
+            Some(c) if c.is_ascii_digit() || c == '-' => self.parse_number(),
+            Some('t') => self.parse_literal("true", JsonValueRef::Boolean(true)),
+            Some('f') => self.parse_literal("false", JsonValueRef::Boolean(false)),
+            Some('n') => self.parse_literal("null", JsonValueRef::Null),
+            Some(c) => Err(JsonError::UnexpectedChar(c, self.span())),
+            None => Err(JsonError::UnexpectedEof),
+        }
+    }
+
+    fn parse_literal(&mut self, word: &str, value: JsonValueRef<'a>) -> Result<JsonValueRef<'a>, JsonError> {
+        let span = self.span();
+        match_literal(self, word, span)?;
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValueRef<'a>, JsonError> {
+        let span = self.span();
+        let start = self.peek_offset();
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || c == '+' || c == '-' {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        let end = self.peek_offset();
+        self.input[start..end]
+            .parse::<f64>()
+            .map(JsonValueRef::Number)
+            .map_err(|_| JsonError::MalformedNumber(span))
+    }
+
+    fn parse_string(&mut self) -> Result<Cow<'a, str>, JsonError> {
+        self.bump(); // Consumir la comilla inicial
+        let start = self.peek_offset();
+        let mut owned: Option<String> = None;
+
+        loop {
+            let before_offset = self.peek_offset();
+            match self.peek() {
+                Some('"') => {
+                    self.bump();
+                    return Ok(match owned {
+                        Some(s) => Cow::Owned(s),
+                        None => Cow::Borrowed(&self.input[start..before_offset]),
+                    });
+                },
+                Some('\\') => {
+                    if owned.is_none() {
+                        owned = Some(self.input[start..before_offset].to_string());
+                    }
+                    let escape_span = self.span();
+                    self.bump(); // Consumimos el caracter de escape
+                    let pushed = match self.bump() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\x08',
+                        Some('f') => '\x0c',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => read_unicode_escape(self, escape_span)?,
+                        _ => return Err(JsonError::InvalidEscape(escape_span)),
+                    };
+                    owned.as_mut().unwrap().push(pushed);
+                },
+                Some(c) => {
+                    if let Some(buf) = owned.as_mut() {
+                        buf.push(c);
+                    }
+                    self.bump();
+                },
+                None => return Err(JsonError::UnexpectedEof),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValueRef<'a>, JsonError> {
+        self.bump(); // Consumir '{'
+        let mut map = HashMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(JsonValueRef::Object(map));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key_span = self.span();
+            let found = self.peek();
+            if found != Some('"') {
+                return Err(JsonError::UnexpectedToken {
+                    found: describe_found(found),
+                    expected: "una key de tipo string".to_string(),
+                    span: key_span,
+                });
+            }
+            let key = self.parse_string()?;
+
+            self.skip_whitespace();
+            let colon_span = self.span();
+            let found = self.bump();
+            if found != Some(':') {
+                return Err(JsonError::UnexpectedToken {
+                    found: describe_found(found),
+                    expected: "':'".to_string(),
+                    span: colon_span,
+                });
+            }
+
+            let value = self.parse_value()?;
+            map.insert(key, value);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                },
+                Some('}') => {
+                    self.bump();
+                    return Ok(JsonValueRef::Object(map));
+                },
+                other => {
+                    return Err(JsonError::UnexpectedToken {
+                        found: describe_found(other),
+                        expected: "',' o '}'".to_string(),
+                        span: self.span(),
+                    })
+                },
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValueRef<'a>, JsonError> {
+        self.bump(); // Consumir '['
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(JsonValueRef::Array(items));
+        }
+
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                },
+                Some(']') => {
+                    self.bump();
+                    return Ok(JsonValueRef::Array(items));
+                },
+                other => {
+                    return Err(JsonError::UnexpectedToken {
+                        found: describe_found(other),
+                        expected: "',' o ']'".to_string(),
+                        span: self.span(),
+                    })
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_without_escapes_is_borrowed() {
+        let value = parse_borrowed("\"hello\"").unwrap();
+        match value {
+            JsonValueRef::String(Cow::Borrowed(s)) => assert_eq!(s, "hello"),
+            other => panic!("expected a borrowed string, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn string_with_escapes_is_owned() {
+        let value = parse_borrowed("\"he said \\\"hi\\\"\"").unwrap();
+        match value {
+            JsonValueRef::String(Cow::Owned(s)) => assert_eq!(s, "he said \"hi\""),
+            other => panic!("expected an owned string, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_trailing_comma_in_object() {
+        assert!(parse_borrowed("{\"a\":1,}").is_err());
+    }
+
+    #[test]
+    fn rejects_trailing_comma_in_array() {
+        assert!(parse_borrowed("[1,2,]").is_err());
+    }
+
+    #[test]
+    fn rejects_unterminated_string() {
+        assert!(matches!(parse_borrowed("\"abc"), Err(JsonError::UnexpectedEof)));
+    }
+}